@@ -51,7 +51,9 @@
 //!
 //! The `unsafe_because` macro requires you to give a reason, and it allows you
 //! to give additional, optional information. You can add the following to
-//! any invocation of `unsafe_because`. (All of these can be repeated.)
+//! any invocation of `unsafe_because`. (All of these can be repeated, up to 4
+//! times each per reason; a 5th call to the same builder method is a
+//! compile-time error, not a runtime panic.)
 //!
 //! * `reason.owner("foo")`: Identifies an owner or expert in this part of the
 //!   design.
@@ -108,12 +110,56 @@
 //! }
 //! ```
 //!
+//! # Annotating unsafe items
+//!
+//! `unsafe_because!` justifies a block of unsafe code, but the obligations that
+//! justification discharges are usually declared somewhere else: the `# Safety`
+//! section of the `unsafe fn`, `unsafe trait`, or `unsafe impl` being called. The
+//! Linux kernel's Rust safety standard draws exactly this distinction between the
+//! *declaration* of a precondition and the *justification* that it is upheld at a
+//! call site. `unsafe_fn_because!`, `unsafe_trait_because!`, and
+//! `unsafe_impl_because!` let you declare named preconditions on the item itself,
+//! using a `requires(...)` clause:
+//!
+//! ```no_run
+//! use unsafety::unsafe_fn_because;
+//!
+//! unsafe_fn_because! {
+//!     requires(
+//!         "p_valid" => "p points to an initialized, readable u8"
+//!     )
+//!     /// Reads the first byte pointed to by `p`.
+//!     pub unsafe fn read_first_byte(p: *mut u8) -> u8 {
+//!         *p
+//!     }
+//! }
+//! ```
+//!
+//! A caller can then name, in its own `unsafe_because!` block, which declared
+//! obligation it is discharging, using `reason.discharges("p_valid")`. This gives
+//! auditors a checkable link between where a precondition is stated and where it
+//! is satisfied, instead of having to re-derive that connection from prose:
+//!
+//! ```no_run
+//! use unsafety::{unsafe_because, USES_FOREIGN_CODE};
+//! # use unsafety::unsafe_fn_because;
+//! # unsafe_fn_because! {
+//! #     requires("p_valid" => "p points to an initialized, readable u8")
+//! #     unsafe fn read_first_byte(p: *mut u8) -> u8 { *p }
+//! # }
+//!
+//! let p: *mut u8 = &mut 0u8;
+//! unsafe_because! {
+//!     USES_FOREIGN_CODE.discharges("p_valid") => {
+//!         read_first_byte(p)
+//!     }
+//! };
+//! ```
+//!
 //! # TODO
 //!
 //! * Improve the list of standard reasons.
 //! * Auditing tools.
-//! * Needs macros for defining unsafe traits and unsafe function signatures, not
-//!   just unsafe code blocks.
 //!
 //! # Future direction
 //!
@@ -124,52 +170,329 @@
 //! to encode that knowledge now, rather than trying to re-discover that knowledge
 //! after a large, mature component has been developed.
 //!
+//! # Audit records
+//!
+//! With the `audit` feature enabled, every `unsafe_because!` invocation emits one
+//! [`UnsafeAuditRecord`] per reason into the `.unsafety_audit` link section of the resulting
+//! object file, using `#[used] #[link_section = ...]`, so the record survives
+//! even though nothing in the crate otherwise refers to it. A `cargo`-side tool
+//! can walk that section in a compiled, *linked* binary to produce a crate-wide
+//! (or workspace-wide) inventory of every unsafe reason in use, which is the
+//! kind of enforcement-by-tooling that scales better than asking reviewers to
+//! notice a missing justification by eye — though, as detailed on
+//! [`UnsafeAuditRecord`], doing so means resolving the section's relocations
+//! first, not just reading its raw bytes. Without the `audit` feature,
+//! `unsafe_because!` remains a true no-op, exactly as it is today.
+//!
+//! # Restricting which reasons are allowed
+//!
+//! The crate's own [future direction](#future-direction) imagines restricting a
+//! component to `unsafe` code for a single accepted reason, such as implementing
+//! a device driver, and nothing else. `allow_unsafe_reasons!` makes that a
+//! `const`-time check today: invoked once per module (or at the crate root), it
+//! declares the only reason ids permitted in `unsafe_because!` invocations for the
+//! rest of that scope, and any reason not in the declared set fails to compile.
+//! This gives a reviewer a grep-free, enforced guarantee about what kinds of
+//! unsafe a component is allowed to contain, matching the "deny by default,
+//! explicit opt-in" posture favored by other unsafe-code review standards.
+//!
+//! ```no_run
+//! use unsafety::{allow_unsafe_reasons, IMPLEMENTS_DEVICE_DRIVER, USES_FOREIGN_CODE};
+//!
+//! allow_unsafe_reasons!(IMPLEMENTS_DEVICE_DRIVER, USES_FOREIGN_CODE);
+//!
+//! unsafe_because! {
+//!     IMPLEMENTS_DEVICE_DRIVER => {
+//!         // ... this compiles ...
+//!     }
+//! }
+//! ```
+//!
+//! `allow_unsafe_reasons!` must be invoked before any `unsafe_because!` calls it
+//! is meant to restrict, since it works by locally redefining `unsafe_because!`
+//! for the remainder of the enclosing module. For that reason, don't also
+//! `use unsafety::unsafe_because;` in a module that calls `allow_unsafe_reasons!`:
+//! an explicit import of the unrestricted macro and the locally-redefined one
+//! would both be in scope by name, which rustc rejects as ambiguous. Calling
+//! `unsafe_because!` unqualified, without importing it, resolves to the
+//! restricted, locally-defined version instead.
+//!
+//! A reason outside the declared set fails to compile, not just at lint time:
+//!
+//! ```compile_fail
+//! use unsafety::{allow_unsafe_reasons, IMPLEMENTS_DEVICE_DRIVER, PERFORMANCE};
+//!
+//! allow_unsafe_reasons!(IMPLEMENTS_DEVICE_DRIVER);
+//!
+//! unsafe_because! {
+//!     PERFORMANCE => {
+//!         // ... PERFORMANCE was never declared above, so this doesn't compile ...
+//!     }
+//! }
+//! ```
+//!
+//! # Type invariants
+//!
+//! A per-block precondition (declared with `requires(...)`, see above) is only
+//! half the story for many unsafe blocks: the other half is often an invariant
+//! of some type, such as "the pointer is always valid while this struct exists",
+//! that the block relies on holding. `unsafe_invariant!` attaches one or more
+//! named, described invariants to a struct or enum, and `unsafe_because!` blocks
+//! can cite one with `reason.relies_on_invariant("TypeName", "invariant_name")`,
+//! letting an auditor trace an unsafe block back to the specific type invariant
+//! it depends on.
+//!
 
 #![no_std]
 
-/// Represents an annotation on an unsafe code block or item. Because these annotations
-/// are intended to have no effect on code generation, this type is empty.
-pub struct UnsafeReason {}
+const MAX_OWNERS: usize = 4;
+const MAX_BUGS: usize = 4;
+const MAX_LINKS: usize = 4;
+const MAX_TAGS: usize = 4;
+const MAX_DISCHARGES: usize = 4;
+const MAX_INVARIANTS: usize = 4;
+
+/// Represents an annotation on an unsafe code block or item.
+///
+/// The fields are `const`-constructible bounded slices of `&'static str` (and, for
+/// `tag`, `&'static str` pairs) so that a whole `UnsafeReason` value, including
+/// every annotation chained onto it, can live in a `const` or `static` — which is
+/// what lets `unsafe_because!` turn one into a linker-section
+/// [`UnsafeAuditRecord`] under the `audit` feature. Under the default feature set
+/// this crate reads only the reason id, via `id()`, and only during `const`
+/// evaluation (for `allow_unsafe_reasons!`), so the compiler discards the fields.
+#[derive(Clone, Copy)]
+pub struct UnsafeReason {
+    reason_id: &'static str,
+    message: Option<&'static str>,
+    owners: [&'static str; MAX_OWNERS],
+    owners_len: usize,
+    bugs: [&'static str; MAX_BUGS],
+    bugs_len: usize,
+    links: [&'static str; MAX_LINKS],
+    links_len: usize,
+    tags: [(&'static str, &'static str); MAX_TAGS],
+    tags_len: usize,
+    discharges: [&'static str; MAX_DISCHARGES],
+    discharges_len: usize,
+    invariants: [(&'static str, &'static str); MAX_INVARIANTS],
+    invariants_len: usize,
+}
 
 impl UnsafeReason {
     /// Starts a new annotation block, given a reason identifier.
-    pub const fn new(_reason_id: &'static str) -> Self {
-        Self {}
+    pub const fn new(reason_id: &'static str) -> Self {
+        Self {
+            reason_id,
+            message: None,
+            owners: [""; MAX_OWNERS],
+            owners_len: 0,
+            bugs: [""; MAX_BUGS],
+            bugs_len: 0,
+            links: [""; MAX_LINKS],
+            links_len: 0,
+            tags: [("", ""); MAX_TAGS],
+            tags_len: 0,
+            discharges: [""; MAX_DISCHARGES],
+            discharges_len: 0,
+            invariants: [("", ""); MAX_INVARIANTS],
+            invariants_len: 0,
+        }
+    }
+
+    /// The reason identifier this annotation was started from.
+    pub const fn id(&self) -> &'static str {
+        self.reason_id
     }
 
     /// An annotation which identifies a bug. This might be a simple identifier, such as `42`,
-    /// although it will typically be a URL in a bug tracking database.
-    pub const fn bug(self, _bug_id: &'static str) -> Self {
+    /// although it will typically be a URL in a bug tracking database. Can be called up to
+    /// `MAX_BUGS` (4) times per `UnsafeReason`; a 5th call panics, which is a compile-time
+    /// error wherever the reason is evaluated in a `const` context (as `unsafe_because!` does).
+    pub const fn bug(mut self, bug_id: &'static str) -> Self {
+        assert!(self.bugs_len < MAX_BUGS, "too many .bug(...) annotations on one UnsafeReason");
+        self.bugs[self.bugs_len] = bug_id;
+        self.bugs_len += 1;
         self
     }
 
     /// An annotation which is an arbitrary message to the reader. This is different from
     /// simple code comments because this annotation will be noticed by auditing tools.
-    pub const fn message(self, _message: &'static str) -> Self {
+    pub const fn message(mut self, message: &'static str) -> Self {
+        self.message = Some(message);
         self
     }
 
     /// An annotation which is the name, user id, or email address of an owner or otherwise
-    /// accountable person.
-    pub const fn owner(self, _owner: &'static str) -> Self {
+    /// accountable person. Can be called up to `MAX_OWNERS` (4) times per `UnsafeReason`; a
+    /// 5th call panics, which is a compile-time error in a `const` context.
+    pub const fn owner(mut self, owner: &'static str) -> Self {
+        assert!(self.owners_len < MAX_OWNERS, "too many .owner(...) annotations on one UnsafeReason");
+        self.owners[self.owners_len] = owner;
+        self.owners_len += 1;
         self
     }
 
     /// An annotation which is a link (URL) to a relevant document, such as a design document.
-    pub const fn link(self, _url: &'static str) -> Self {
+    /// Can be called up to `MAX_LINKS` (4) times per `UnsafeReason`; a 5th call panics,
+    /// which is a compile-time error in a `const` context.
+    pub const fn link(mut self, url: &'static str) -> Self {
+        assert!(self.links_len < MAX_LINKS, "too many .link(...) annotations on one UnsafeReason");
+        self.links[self.links_len] = url;
+        self.links_len += 1;
+        self
+    }
+
+    /// An annotation which is an arbitrary key-value pair. Can be called up to `MAX_TAGS` (4)
+    /// times per `UnsafeReason`; a 5th call panics, which is a compile-time error in a
+    /// `const` context.
+    pub const fn tag(mut self, tag: &'static str, value: &'static str) -> Self {
+        assert!(self.tags_len < MAX_TAGS, "too many .tag(...) annotations on one UnsafeReason");
+        self.tags[self.tags_len] = (tag, value);
+        self.tags_len += 1;
         self
     }
 
-    /// An annotation which is an arbitrary key-value pair.
-    pub const fn tag(self, _tag: &'static str, _value: &'static str) -> Self {
+    /// An annotation which names a precondition, declared by `unsafe_fn_because!`,
+    /// `unsafe_trait_because!`, or `unsafe_impl_because!` on the item being used,
+    /// that this `unsafe_because!` block discharges. This lets an auditor trace a
+    /// justification back to the specific declared obligation it satisfies. Can be
+    /// called up to `MAX_DISCHARGES` (4) times per `UnsafeReason`; a 5th call panics,
+    /// which is a compile-time error in a `const` context.
+    pub const fn discharges(mut self, precondition_id: &'static str) -> Self {
+        assert!(
+            self.discharges_len < MAX_DISCHARGES,
+            "too many .discharges(...) annotations on one UnsafeReason"
+        );
+        self.discharges[self.discharges_len] = precondition_id;
+        self.discharges_len += 1;
         self
     }
+
+    /// An annotation which names a type invariant, declared by `unsafe_invariant!`
+    /// on `type_name`, that this `unsafe_because!` block relies on for its
+    /// correctness. This lets an auditor trace an unsafe block back to the
+    /// specific declared type invariant it depends on, rather than re-deriving
+    /// that reasoning from free-text comments. Can be called up to
+    /// `MAX_INVARIANTS` (4) times per `UnsafeReason`; a 5th call panics, which
+    /// is a compile-time error in a `const` context.
+    pub const fn relies_on_invariant(mut self, type_name: &'static str, invariant_name: &'static str) -> Self {
+        assert!(
+            self.invariants_len < MAX_INVARIANTS,
+            "too many .relies_on_invariant(...) annotations on one UnsafeReason"
+        );
+        self.invariants[self.invariants_len] = (type_name, invariant_name);
+        self.invariants_len += 1;
+        self
+    }
+}
+
+/// A serialized record of one `unsafe_because!` invocation, emitted into the
+/// `.unsafety_audit` link section when the `audit` feature is enabled.
+///
+/// # On-disk format
+///
+/// Each record is a `#[repr(C)]` value of this type, placed in the
+/// `.unsafety_audit` section by a `#[used] static`. The bounded arrays and
+/// `*_len` fields give each record a fixed, `Sized` layout, so records in the
+/// section are laid out back-to-back with no separate length-prefixed framing
+/// needed between them. `file` and `line` identify the `unsafe_because!` call
+/// site via `file!()`/`line!()`.
+///
+/// The `&'static str` fields, however, are fat pointers (data pointer + byte
+/// length), not inline bytes: on disk, the data-pointer half of each one is a
+/// zero-filled placeholder that only becomes the real string address once the
+/// object's relocations (e.g. `R_X86_64_RELATIVE` entries in `.rela.dyn`, or
+/// their rlib/COFF/Mach-O equivalents) are applied. A tool walking the section
+/// in a compiled, linked binary must therefore resolve those relocations
+/// (loading the object as the runtime linker would, e.g. with `goblin`'s
+/// relocation support) before the string fields are meaningful, and a tool
+/// walking an unlinked `rlib` faces the harder problem that those relocations
+/// may still be symbolic and unresolved. This crate does not yet ship such a
+/// tool or a relocation-free wire format; both are future work.
+#[cfg(feature = "audit")]
+#[repr(C)]
+pub struct UnsafeAuditRecord {
+    pub reason_id: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub message: Option<&'static str>,
+    pub owners: [&'static str; MAX_OWNERS],
+    pub owners_len: usize,
+    pub bugs: [&'static str; MAX_BUGS],
+    pub bugs_len: usize,
+    pub links: [&'static str; MAX_LINKS],
+    pub links_len: usize,
+    pub tags: [(&'static str, &'static str); MAX_TAGS],
+    pub tags_len: usize,
+    pub discharges: [&'static str; MAX_DISCHARGES],
+    pub discharges_len: usize,
+    pub invariants: [(&'static str, &'static str); MAX_INVARIANTS],
+    pub invariants_len: usize,
+}
+
+#[cfg(feature = "audit")]
+impl UnsafeAuditRecord {
+    /// Builds an audit record from a reason and its call site. Called by
+    /// `unsafe_because!`; not normally constructed directly.
+    pub const fn new(reason: UnsafeReason, file: &'static str, line: u32) -> Self {
+        Self {
+            reason_id: reason.reason_id,
+            file,
+            line,
+            message: reason.message,
+            owners: reason.owners,
+            owners_len: reason.owners_len,
+            bugs: reason.bugs,
+            bugs_len: reason.bugs_len,
+            links: reason.links,
+            links_len: reason.links_len,
+            tags: reason.tags,
+            tags_len: reason.tags_len,
+            discharges: reason.discharges,
+            discharges_len: reason.discharges_len,
+            invariants: reason.invariants,
+            invariants_len: reason.invariants_len,
+        }
+    }
+}
+
+/// Emits an `UnsafeAuditRecord` for `$reason` into the `.unsafety_audit` link
+/// section. Used by `unsafe_because!`; a true no-op unless the `audit` feature is
+/// enabled.
+#[cfg(feature = "audit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unsafety_audit_record {
+    ($reason:expr) => {
+        #[used]
+        #[link_section = ".unsafety_audit"]
+        static _UNSAFETY_AUDIT_RECORD: $crate::UnsafeAuditRecord =
+            $crate::UnsafeAuditRecord::new($reason, file!(), line!());
+    };
+}
+
+#[cfg(not(feature = "audit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unsafety_audit_record {
+    ($reason:expr) => {};
 }
 
 /// Annotations a block of unsafe code. See module docs.
-/// 
+///
 /// This macro uses `reason => body` syntax in order to avoid the "right-ward creep"
 /// that would occur if the body was always wrapped in another level of braces.
+///
+/// Each `$reason` is bound through a `const _: UnsafeReason = $reason;` before
+/// it's used, regardless of the `audit` feature. `UnsafeReason`'s builder
+/// methods (`bug`, `owner`, etc.) `assert!` that their bounded arrays aren't
+/// overfull, but an `assert!` in a `const fn` is only actually evaluated at
+/// compile time when its result is forced into a `const` context; without this,
+/// a reason built up entirely inline (as opposed to bound to an explicit
+/// `const` or `static`, as the audit feature's record `static` happens to do) would only panic if
+/// the `unsafe_because!` block were ever executed, contradicting this crate's
+/// no-runtime-cost, checked-at-compile-time design.
 #[macro_export]
 macro_rules! unsafe_because {
     (
@@ -181,7 +504,11 @@ macro_rules! unsafe_because {
     ) => {
         {
             $(
-                $crate::unsafe_reason($reason);
+                {
+                    const _: $crate::UnsafeReason = $reason;
+                    $crate::unsafe_reason($reason);
+                    $crate::__unsafety_audit_record!($reason);
+                }
             )*
             unsafe {
                 $($body)*
@@ -192,7 +519,9 @@ macro_rules! unsafe_because {
         $reason:expr => $($body:tt)*
     ) => {
         {
+            const _: $crate::UnsafeReason = $reason;
             $crate::unsafe_reason($reason);
+            $crate::__unsafety_audit_record!($reason);
             unsafe {
                 $($body)*
             }
@@ -207,6 +536,325 @@ pub const fn unsafe_reason(_reason: UnsafeReason) {
     // nothing
 }
 
+/// This function does nothing. It exists only so that `unsafe_fn_because!`,
+/// `unsafe_trait_because!`, and `unsafe_impl_because!` can verify that the
+/// preconditions given in a `requires(...)` clause are syntactically valid.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn unsafe_precondition(_id: &'static str, _description: &'static str) {
+    // nothing
+}
+
+/// Declares an `unsafe fn` together with the named preconditions from its
+/// `# Safety` section. See the module docs for how the declared precondition ids
+/// are meant to be cited from `unsafe_because!` blocks at call sites, via
+/// `reason.discharges(id)`.
+///
+/// The `requires(...)` clause comes before the item rather than inside its
+/// signature, and the macro matches through the `unsafe fn` keywords
+/// themselves (rather than accepting any `$item:item`) so that decorating a
+/// safe fn is a compile error instead of a silently-ignored no-op. Everything
+/// after the `fn` keyword — the name, generics, parameters, return type,
+/// where-clause, and body — is still slurped as raw `$($rest:tt)*` and
+/// spliced back unparsed, so arbitrary `unsafe fn` signatures are accepted
+/// without this macro having to hand-parse generics or return types itself.
+///
+/// This macro is zero-cost: it expands to the ordinary `unsafe fn` plus metadata
+/// that has no effect on code generation.
+///
+/// Decorating a fn that isn't `unsafe` fails to compile, rather than silently
+/// doing nothing:
+///
+/// ```compile_fail
+/// use unsafety::unsafe_fn_because;
+///
+/// unsafe_fn_because! {
+///     requires("x" => "some condition on x")
+///     fn not_actually_unsafe(x: u8) -> u8 {
+///         x
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! unsafe_fn_because {
+    (
+        requires( $( $pid:literal => $pdesc:literal ),+ $(,)? )
+        $(#[$meta:meta])*
+        $vis:vis unsafe fn $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis unsafe fn $($rest)*
+
+        const _: () = {
+            $(
+                $crate::unsafe_precondition($pid, $pdesc);
+            )+
+        };
+    };
+}
+
+/// Declares an `unsafe trait` together with the named preconditions that
+/// implementers must uphold. See [`unsafe_fn_because!`] and the module docs.
+///
+/// Decorating a trait that isn't `unsafe` fails to compile, rather than
+/// silently doing nothing:
+///
+/// ```compile_fail
+/// use unsafety::unsafe_trait_because;
+///
+/// unsafe_trait_because! {
+///     requires("x" => "some condition on x")
+///     trait NotActuallyUnsafe {}
+/// }
+/// ```
+#[macro_export]
+macro_rules! unsafe_trait_because {
+    (
+        requires( $( $pid:literal => $pdesc:literal ),+ $(,)? )
+        $(#[$meta:meta])*
+        $vis:vis unsafe trait $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis unsafe trait $($rest)*
+
+        const _: () = {
+            $(
+                $crate::unsafe_precondition($pid, $pdesc);
+            )+
+        };
+    };
+}
+
+/// Declares an `unsafe impl` together with the named preconditions that justify
+/// it. See [`unsafe_fn_because!`] and the module docs.
+///
+/// Decorating an `impl` that isn't `unsafe` fails to compile, rather than
+/// silently doing nothing:
+///
+/// ```compile_fail
+/// use unsafety::unsafe_impl_because;
+///
+/// trait Marker {}
+///
+/// unsafe_impl_because! {
+///     requires("x" => "some condition on x")
+///     impl Marker for u8 {}
+/// }
+/// ```
+#[macro_export]
+macro_rules! unsafe_impl_because {
+    (
+        requires( $( $pid:literal => $pdesc:literal ),+ $(,)? )
+        unsafe impl $($rest:tt)*
+    ) => {
+        unsafe impl $($rest)*
+
+        const _: () = {
+            $(
+                $crate::unsafe_precondition($pid, $pdesc);
+            )+
+        };
+    };
+}
+
+/// This function does nothing. It exists only so that `unsafe_invariant!` can
+/// verify that the invariants given to it are syntactically valid.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn unsafe_type_invariant(_type_name: &'static str, _invariant_id: &'static str, _description: &'static str) {
+    // nothing
+}
+
+/// Declares one or more named, described invariants on a struct or enum, e.g.
+/// "the pointer is always valid while this struct exists". The Linux kernel
+/// safety standard treats a type's `# Invariants` as a contract distinct from
+/// (and relied on by) the per-block preconditions of the unsafe code that uses
+/// the type; this macro gives that contract a name that `unsafe_because!` blocks
+/// can cite via `reason.relies_on_invariant("TypeName", "invariant_name")`,
+/// instead of re-deriving the reasoning from free-text comments.
+///
+/// The type's name must be repeated before `invariants(...)`. Rather than
+/// capturing the struct/enum as a single opaque `$item:item` (which can't be
+/// destructured back apart to recover its name), this macro matches up to and
+/// including the `struct`/`enum` keyword and its identifier itself, then
+/// slurps the remainder (generics, where-clause, fields) as raw `$($rest:tt)*`
+/// and splices it back unparsed — so any struct or enum, generic or not, tuple
+/// or field-bodied, is still accepted without this macro having to understand
+/// its generics. That lets the leading `$name` be checked, at compile time,
+/// against the type's real identifier, so invariants can't silently be
+/// recorded under a name that doesn't match the type they're attached to.
+///
+/// This macro is zero-cost: it expands to the ordinary `struct`/`enum` plus
+/// metadata that has no effect on code generation.
+///
+/// ```no_run
+/// use unsafety::unsafe_invariant;
+///
+/// unsafe_invariant! {
+///     InitializedSlice,
+///     invariants(
+///         "ptr_valid" => "ptr is non-null, well-aligned, and points to `len` initialized bytes"
+///     )
+///     /// A non-null, well-aligned pointer into a buffer of `len` initialized bytes.
+///     pub struct InitializedSlice {
+///         ptr: *mut u8,
+///         len: usize,
+///     }
+/// }
+/// ```
+///
+/// A leading name that doesn't match the decorated type fails to compile,
+/// rather than silently recording the invariants under the wrong name:
+///
+/// ```compile_fail
+/// use unsafety::unsafe_invariant;
+///
+/// unsafe_invariant! {
+///     TotallyWrongName,
+///     invariants(
+///         "ptr_valid" => "ptr is non-null, well-aligned, and points to `len` initialized bytes"
+///     )
+///     pub struct InitializedSlice {
+///         ptr: *mut u8,
+///         len: usize,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! unsafe_invariant {
+    (
+        $name:ident,
+        invariants( $( $iid:literal => $idesc:literal ),+ $(,)? )
+        $(#[$meta:meta])*
+        $vis:vis struct $sname:ident $($rest:tt)*
+    ) => {
+        const _: () = assert!(
+            $crate::const_str_eq(stringify!($name), stringify!($sname)),
+            concat!(
+                "unsafe_invariant!: name `",
+                stringify!($name),
+                "` does not match the struct it decorates, `",
+                stringify!($sname),
+                "`",
+            )
+        );
+
+        $(#[$meta])*
+        $vis struct $sname $($rest)*
+
+        const _: () = {
+            $(
+                $crate::unsafe_type_invariant(stringify!($name), $iid, $idesc);
+            )+
+        };
+    };
+    (
+        $name:ident,
+        invariants( $( $iid:literal => $idesc:literal ),+ $(,)? )
+        $(#[$meta:meta])*
+        $vis:vis enum $sname:ident $($rest:tt)*
+    ) => {
+        const _: () = assert!(
+            $crate::const_str_eq(stringify!($name), stringify!($sname)),
+            concat!(
+                "unsafe_invariant!: name `",
+                stringify!($name),
+                "` does not match the enum it decorates, `",
+                stringify!($sname),
+                "`",
+            )
+        );
+
+        $(#[$meta])*
+        $vis enum $sname $($rest)*
+
+        const _: () = {
+            $(
+                $crate::unsafe_type_invariant(stringify!($name), $iid, $idesc);
+            )+
+        };
+    };
+}
+
+#[doc(hidden)]
+pub const fn const_str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `const`-evaluated: panics (at compile time, since it is only ever called from
+/// a `const` context) unless `reason_id` is one of `allowed`. Used by
+/// `allow_unsafe_reasons!`; not normally called directly.
+#[doc(hidden)]
+pub const fn __unsafety_assert_reason_allowed(reason_id: &str, allowed: &[&str]) {
+    let mut i = 0;
+    while i < allowed.len() {
+        if const_str_eq(reason_id, allowed[i]) {
+            return;
+        }
+        i += 1;
+    }
+    panic!("unsafe_because!: this reason is not permitted by allow_unsafe_reasons! in this module");
+}
+
+/// Declares the set of `UnsafeReason` ids permitted in `unsafe_because!`
+/// invocations for the rest of the enclosing module. See the module docs.
+#[macro_export]
+macro_rules! allow_unsafe_reasons {
+    ( $($reason:ident),+ $(,)? ) => {
+        $crate::__allow_unsafe_reasons_with_dollar!( ($($reason),+) $ );
+    };
+}
+
+/// Implementation detail of `allow_unsafe_reasons!`. Takes a leading `$` as a
+/// `tt`, passed in by `allow_unsafe_reasons!`, so that the locally-redefined
+/// `unsafe_because!` below can itself use `$` metavariables without requiring
+/// the unstable metavariable-escaping feature. The `$` has to arrive after the
+/// reason list, not before it: a literal `$` directly followed by `,` (as
+/// opposed to an identifier, `(`, or another `$`) is itself a hard parse error
+/// in a macro transcriber, so the reasons are grouped in parens ahead of it
+/// instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __allow_unsafe_reasons_with_dollar {
+    ( ($($reason:ident),+) $dollar:tt ) => {
+        #[allow(unused_macros)]
+        macro_rules! unsafe_because {
+            ( [ $dollar( $dollar r:expr ),+ ] => $dollar( $dollar body:tt )* ) => {
+                {
+                    $dollar(
+                        const _: () = $crate::__unsafety_assert_reason_allowed(
+                            $dollar r.id(),
+                            &[ $( stringify!($reason) ),+ ],
+                        );
+                    )+
+                    $crate::unsafe_because! { [ $dollar( $dollar r ),+ ] => $dollar( $dollar body )* }
+                }
+            };
+            ( $dollar r:expr => $dollar( $dollar body:tt )* ) => {
+                {
+                    const _: () = $crate::__unsafety_assert_reason_allowed(
+                        $dollar r.id(),
+                        &[ $( stringify!($reason) ),+ ],
+                    );
+                    $crate::unsafe_because! { $dollar r => $dollar( $dollar body )* }
+                }
+            };
+        }
+    };
+}
+
 macro_rules! standard_reasons {
     ( $(
         $(#[$a:meta])*
@@ -258,4 +906,189 @@ standard_reasons! {
     /// Using an intrinsic instruction on a processor that does not implement the
     /// intrinsic is undefined behavior.
     USES_VECTOR_INTRINSICS,
+
+    /// The unsafe code calls `core::hint::unreachable_unchecked()` (typically via
+    /// `unreachable_unchecked_because!`) because the author has proven that
+    /// control flow can never reach that point.
+    ASSERTS_UNREACHABLE,
+
+    /// The unsafe code asserts an invariant to the optimizer (typically via
+    /// `assume_because!`) that the author has proven always holds, such as a
+    /// length being within a capacity.
+    ASSUMES_INVARIANT,
+}
+
+/// Registers `$reason` and then calls `core::hint::unreachable_unchecked()`.
+///
+/// `unreachable_unchecked` is a frequent source of `unsafe` whose correctness
+/// rests entirely on an invariant the author believes holds: that control flow
+/// can never reach this point. This macro pairs the call with the usual
+/// justification, instead of leaving it as a bare `unsafe` block.
+///
+/// ```no_run
+/// use unsafety::{unreachable_unchecked_because, ASSERTS_UNREACHABLE};
+///
+/// fn describe(x: u8) -> &'static str {
+///     match x % 3 {
+///         0 => "fizz",
+///         1 => "buzz",
+///         2 => "fizzbuzz",
+///         _ => unreachable_unchecked_because! {
+///             ASSERTS_UNREACHABLE.message("x % 3 is always 0, 1, or 2")
+///         },
+///     }
+/// }
+/// ```
+///
+/// Like `unsafe_because!`, `$reason` is bound through a `const _: UnsafeReason =
+/// $reason;` before it's used, so `UnsafeReason`'s builder-method overflow
+/// `assert!`s are forced to run at compile time. Without that binding, a reason
+/// built up entirely inline inside an `unreachable_unchecked_because!` arm would
+/// only panic if that (supposedly unreachable) arm actually ran, which could
+/// ship an overfull reason without ever failing the build:
+///
+/// ```compile_fail
+/// use unsafety::{unreachable_unchecked_because, ASSERTS_UNREACHABLE};
+///
+/// unreachable_unchecked_because! {
+///     ASSERTS_UNREACHABLE.bug("1").bug("2").bug("3").bug("4").bug("5")
+/// };
+/// ```
+#[macro_export]
+macro_rules! unreachable_unchecked_because {
+    ( $reason:expr ) => {{
+        const _: $crate::UnsafeReason = $reason;
+        $crate::unsafe_reason($reason);
+        $crate::__unsafety_audit_record!($reason);
+        unsafe { core::hint::unreachable_unchecked() }
+    }};
+}
+
+/// Registers `$reason` and then asserts `$cond` as an invariant the optimizer may
+/// rely on, in the same spirit as `unreachable_unchecked_because!`: the
+/// correctness of the hint rests entirely on the author having proven `$cond`
+/// always holds.
+///
+/// On stable Rust this lowers to the standard `debug_assert!` followed by
+/// `core::hint::unreachable_unchecked()` idiom, which is checked in debug builds
+/// and becomes a true compiler hint (rather than a no-op) once optimizations are
+/// enabled.
+///
+/// ```no_run
+/// use unsafety::{assume_because, ASSUMES_INVARIANT};
+///
+/// fn get_unchecked(v: &[u8], len: usize, cap: usize) -> &[u8] {
+///     assume_because! { ASSUMES_INVARIANT.message("len <= cap") => len <= cap };
+///     &v[..len]
+/// }
+/// ```
+///
+/// Like `unsafe_because!`, `$reason` is bound through a `const _: UnsafeReason =
+/// $reason;` before it's used, so `UnsafeReason`'s builder-method overflow
+/// `assert!`s are forced to run at compile time rather than only when `$cond`
+/// happens to be checked at runtime:
+///
+/// ```compile_fail
+/// use unsafety::{assume_because, ASSUMES_INVARIANT};
+///
+/// assume_because! {
+///     ASSUMES_INVARIANT.owner("a").owner("b").owner("c").owner("d").owner("e") => true
+/// };
+/// ```
+#[macro_export]
+macro_rules! assume_because {
+    ( $reason:expr => $cond:expr ) => {{
+        const _: $crate::UnsafeReason = $reason;
+        $crate::unsafe_reason($reason);
+        $crate::__unsafety_audit_record!($reason);
+        unsafe { $crate::__unsafety_assume($cond) }
+    }};
+}
+
+/// Asserts, in debug builds, that `cond` holds, and then tells the optimizer it
+/// may assume `cond` holds by triggering undefined behavior if it does not.
+/// Called by `assume_because!`; not normally called directly.
+///
+/// # Safety
+///
+/// `cond` must always be `true`.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn __unsafety_assume(cond: bool) {
+    debug_assert!(cond);
+    if !cond {
+        core::hint::unreachable_unchecked();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_str_eq_matches() {
+        assert!(const_str_eq("foo", "foo"));
+        assert!(const_str_eq("", ""));
+    }
+
+    #[test]
+    fn const_str_eq_rejects_different_content_or_length() {
+        assert!(!const_str_eq("foo", "bar"));
+        assert!(!const_str_eq("foo", "foobar"));
+        assert!(!const_str_eq("foobar", "foo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .bug(...) annotations")]
+    fn bug_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_BUGS {
+            reason = reason.bug("b");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .owner(...) annotations")]
+    fn owner_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_OWNERS {
+            reason = reason.owner("o");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .link(...) annotations")]
+    fn link_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_LINKS {
+            reason = reason.link("https://example.com");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .tag(...) annotations")]
+    fn tag_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_TAGS {
+            reason = reason.tag("k", "v");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .discharges(...) annotations")]
+    fn discharges_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_DISCHARGES {
+            reason = reason.discharges("p");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many .relies_on_invariant(...) annotations")]
+    fn relies_on_invariant_overflow_panics() {
+        let mut reason = UnsafeReason::new("TEST");
+        for _ in 0..=MAX_INVARIANTS {
+            reason = reason.relies_on_invariant("Type", "inv");
+        }
+    }
 }